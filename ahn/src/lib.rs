@@ -1,7 +1,18 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![forbid(unsafe_code)]
 //! ahn: Minimal Energy Control (MEC) crate.
 //! Architect: Alexis Adams (@devdollzai)
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+// Re-export the shared control-loop invariant macros so downstream firmware
+// can reach them as `ahn::assert_within!` & co.
+pub use mec::{
+    assert_converged, assert_ge, assert_le, assert_within, debug_assert_converged,
+    debug_assert_ge, debug_assert_le, debug_assert_within,
+};
+
 /// Health check function - returns crate identifier
 pub fn ping() -> &'static str {
     "ahn::ok"
@@ -15,4 +26,4 @@ mod tests {
     fn ping_ok() {
         assert_eq!(ping(), "ahn::ok");
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,126 @@
+//! Declarative invariant checks for control-loop safety.
+//!
+//! These macros depend only on `core` (expression text via
+//! [`core::stringify`], reporting via [`core::panic`]), so they remain
+//! usable under `#![no_std]`. Each `debug_*` variant is gated on
+//! `debug_assertions` exactly like the standard library's
+//! [`debug_assert!`], compiling to a no-op in release builds.
+//!
+//! On failure every macro reports the failing expression text together
+//! with the actual value and the bound(s) it violated.
+
+/// Asserts that `value` lies within the inclusive range `[lo, hi]`.
+#[macro_export]
+macro_rules! assert_within {
+    ($value:expr, $lo:expr, $hi:expr $(,)?) => {{
+        let value = $value;
+        let lo = $lo;
+        let hi = $hi;
+        if value < lo || value > hi {
+            core::panic!(
+                "invariant failed: `{}` within [`{}`, `{}`] (value: {:?}, bounds: [{:?}, {:?}])",
+                core::stringify!($value),
+                core::stringify!($lo),
+                core::stringify!($hi),
+                value,
+                lo,
+                hi,
+            );
+        }
+    }};
+}
+
+/// Asserts that `lhs` is greater than or equal to `rhs`.
+#[macro_export]
+macro_rules! assert_ge {
+    ($lhs:expr, $rhs:expr $(,)?) => {{
+        let lhs = $lhs;
+        let rhs = $rhs;
+        if !(lhs >= rhs) {
+            core::panic!(
+                "invariant failed: `{}` >= `{}` (value: {:?}, bound: {:?})",
+                core::stringify!($lhs),
+                core::stringify!($rhs),
+                lhs,
+                rhs,
+            );
+        }
+    }};
+}
+
+/// Asserts that `lhs` is less than or equal to `rhs`.
+#[macro_export]
+macro_rules! assert_le {
+    ($lhs:expr, $rhs:expr $(,)?) => {{
+        let lhs = $lhs;
+        let rhs = $rhs;
+        if !(lhs <= rhs) {
+            core::panic!(
+                "invariant failed: `{}` <= `{}` (value: {:?}, bound: {:?})",
+                core::stringify!($lhs),
+                core::stringify!($rhs),
+                lhs,
+                rhs,
+            );
+        }
+    }};
+}
+
+/// Asserts that a control error `err` has converged within tolerance
+/// `tol`, i.e. `-tol <= err <= tol`.
+#[macro_export]
+macro_rules! assert_converged {
+    ($err:expr, $tol:expr $(,)?) => {{
+        let err = $err;
+        let tol = $tol;
+        if !(err <= tol && err >= -tol) {
+            core::panic!(
+                "invariant failed: `{}` converged within `{}` (error: {:?}, tol: {:?})",
+                core::stringify!($err),
+                core::stringify!($tol),
+                err,
+                tol,
+            );
+        }
+    }};
+}
+
+/// [`assert_within!`] that is compiled out unless `debug_assertions` are on.
+#[macro_export]
+macro_rules! debug_assert_within {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_within!($($arg)*);
+        }
+    };
+}
+
+/// [`assert_ge!`] that is compiled out unless `debug_assertions` are on.
+#[macro_export]
+macro_rules! debug_assert_ge {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_ge!($($arg)*);
+        }
+    };
+}
+
+/// [`assert_le!`] that is compiled out unless `debug_assertions` are on.
+#[macro_export]
+macro_rules! debug_assert_le {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_le!($($arg)*);
+        }
+    };
+}
+
+/// [`assert_converged!`] that is compiled out unless `debug_assertions` are on.
+#[macro_export]
+macro_rules! debug_assert_converged {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_converged!($($arg)*);
+        }
+    };
+}
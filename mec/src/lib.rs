@@ -0,0 +1,51 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![forbid(unsafe_code)]
+//! mec: shared Minimal Energy Control (MEC) primitives.
+//! Architect: Alexis Adams (@devdollzai)
+//!
+//! Houses the canonical, `no_std` invariant/assertion macros reused by the
+//! per-domain MEC crates (`ahn`, `dsg`, `tfi`, `olo`, `ezc`) so there is a
+//! single implementation rather than one copy per crate.
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+/// Declarative invariant/assertion macros for control-loop safety checks.
+pub mod invariant;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        assert_converged, assert_ge, assert_le, assert_within, debug_assert_converged,
+        debug_assert_ge, debug_assert_le, debug_assert_within,
+    };
+
+    #[test]
+    fn invariants_hold() {
+        assert_within!(5, 0, 10);
+        assert_ge!(10, 10);
+        assert_le!(1, 2);
+        assert_converged!(0.01_f64, 0.1_f64);
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_bounds_panics() {
+        assert_within!(42, 0, 10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn not_converged_panics() {
+        assert_converged!(0.5_f64, 0.1_f64);
+    }
+
+    #[test]
+    fn debug_variants_check_under_debug_assertions() {
+        // Built with `debug_assertions` on under `cargo test`, so these run.
+        debug_assert_within!(5, 0, 10);
+        debug_assert_ge!(2, 1);
+        debug_assert_le!(1, 2);
+        debug_assert_converged!(0.0_f64, 0.1_f64);
+    }
+}